@@ -0,0 +1,91 @@
+use serenity::{
+    http::CacheHttp,
+    model::{channel::Message, id::ChannelId},
+    Result as SerenityResult,
+};
+
+// Discord caps messages at 2000 characters; leave headroom for the
+// surrounding code fence so a chunk never tips a message over that limit.
+const MAX_CHUNK_LEN: usize = 1990;
+
+pub async fn send_reply(
+    ctx: impl CacheHttp,
+    msg: &Message,
+    content: impl std::fmt::Display,
+) -> SerenityResult<Message> {
+    msg.reply(ctx, content).await
+}
+
+/// Splits `content` on line boundaries into chunks that stay under Discord's
+/// message limit once wrapped in a code fence, then sends them as sequential
+/// messages to `channel_id`.
+pub async fn send_split_in_card(
+    ctx: impl CacheHttp,
+    channel_id: ChannelId,
+    content: &str,
+) -> SerenityResult<Vec<Message>> {
+    let mut messages = Vec::new();
+
+    for chunk in split_into_chunks(content, MAX_CHUNK_LEN) {
+        let message = channel_id.send_message(ctx.http(), |m| {
+            m.content(format!("```\n{}\n```", chunk))
+        }).await?;
+
+        messages.push(message);
+    }
+
+    Ok(messages)
+}
+
+fn split_into_chunks(content: &str, max_len: usize) -> Vec<String> {
+    let mut chunks = Vec::new();
+    let mut current = String::new();
+
+    for line in content.lines() {
+        for piece in split_oversized_line(line, max_len) {
+            if !current.is_empty() && current.len() + piece.len() + 1 > max_len {
+                chunks.push(std::mem::take(&mut current));
+            }
+
+            if !current.is_empty() {
+                current.push('\n');
+            }
+
+            current.push_str(piece);
+        }
+    }
+
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+// A single line can still be longer than `max_len` on its own (e.g. one huge
+// word), in which case no line boundary ever gives `split_into_chunks`
+// anywhere to break — so hard-split it into `max_len`-sized pieces here.
+fn split_oversized_line(line: &str, max_len: usize) -> Vec<&str> {
+    if line.len() <= max_len {
+        return vec![line];
+    }
+
+    let mut pieces = Vec::new();
+    let mut rest = line;
+
+    while rest.len() > max_len {
+        let mut boundary = max_len;
+
+        while !rest.is_char_boundary(boundary) {
+            boundary -= 1;
+        }
+
+        let (piece, remainder) = rest.split_at(boundary);
+        pieces.push(piece);
+        rest = remainder;
+    }
+
+    pieces.push(rest);
+
+    pieces
+}
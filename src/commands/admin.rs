@@ -0,0 +1,90 @@
+use serenity::{
+    prelude::*,
+    cache::Cache,
+    model::{
+        channel::Message,
+        guild::Member,
+    },
+    framework::standard::{
+        CommandResult,
+        macros::*,
+    },
+};
+
+#[command]
+#[only_in(guilds)]
+#[required_permissions("KICK_MEMBERS")]
+async fn kick(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+    let sender = msg.member(ctx).await?;
+
+    for user in &msg.mentions {
+        let target = match guild_id.member(ctx, user.id).await {
+            Ok(member) => member,
+            Err(err) => {
+                msg.reply(ctx, format!("Couldn't fetch {}: {}", user.name, err)).await?;
+                continue;
+            }
+        };
+
+        let reply = if !outranks(&sender, &target, &ctx.cache).await {
+            format!("Can't kick {}: not high enough in the role hierarchy", target.display_name())
+        } else {
+            match target.kick(&ctx.http).await {
+                Ok(_) => format!("{} was kicked", target.display_name()),
+                Err(err) => format!("Couldn't kick {}: {}", target.display_name(), err),
+            }
+        };
+
+        msg.reply(ctx, reply).await?;
+    }
+
+    Ok(())
+}
+
+#[command]
+#[only_in(guilds)]
+#[required_permissions("BAN_MEMBERS")]
+async fn ban(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+    let sender = msg.member(ctx).await?;
+
+    for user in &msg.mentions {
+        let target = match guild_id.member(ctx, user.id).await {
+            Ok(member) => member,
+            Err(err) => {
+                msg.reply(ctx, format!("Couldn't fetch {}: {}", user.name, err)).await?;
+                continue;
+            }
+        };
+
+        let reply = if !outranks(&sender, &target, &ctx.cache).await {
+            format!("Can't ban {}: not high enough in the role hierarchy", target.display_name())
+        } else {
+            match target.ban(&ctx.http, 0).await {
+                Ok(_) => format!("{} was banned", target.display_name()),
+                Err(err) => format!("Couldn't ban {}: {}", target.display_name(), err),
+            }
+        };
+
+        msg.reply(ctx, reply).await?;
+    }
+
+    Ok(())
+}
+
+// Discord's role hierarchy: a member can only act on another member ranked
+// strictly below them. A member with no roles can't outrank anyone, while a
+// target with no roles is always fair game.
+pub(crate) async fn outranks(sender: &Member, target: &Member, cache: impl AsRef<Cache>) -> bool {
+    let sender_position = match sender.highest_role_info(&cache).await {
+        Some((_, position)) => position,
+        None => return false,
+    };
+
+    let target_position = target.highest_role_info(&cache).await
+        .map(|(_, position)| position)
+        .unwrap_or(i64::MIN);
+
+    sender_position > target_position
+}
@@ -0,0 +1,41 @@
+use serenity::{
+    prelude::*,
+    model::channel::Message,
+    framework::standard::{
+        Args, CommandResult,
+        macros::*,
+    },
+};
+
+use crate::config::update_guild_options;
+
+#[command("ghost_ping")]
+#[aliases("ghostping")]
+#[only_in(guilds)]
+#[required_permissions("ADMINISTRATOR")]
+#[min_args(1)]
+#[max_args(1)]
+async fn ghost_ping(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+    let enable = match args.single::<String>()?.as_str() {
+        "on" | "enable" => true,
+        "off" | "disable" => false,
+        other => {
+            msg.reply(ctx, format!("Unknown option `{}`, expected `on` or `off`", other)).await?;
+
+            return Ok(());
+        }
+    };
+
+    update_guild_options(ctx, guild_id, |options| options.ghost_ping = enable).await;
+
+    let message = if enable {
+        "Ghost ping detection is now enabled for this server"
+    } else {
+        "Ghost ping detection is now disabled for this server"
+    };
+
+    msg.reply(ctx, message).await?;
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use serenity::{
+    prelude::*,
+    model::{
+        channel::Message,
+        misc::Mentionable,
+    },
+    framework::standard::{
+        Args, CommandResult,
+        macros::*,
+    },
+};
+use songbird::input;
+
+#[command]
+#[only_in(guilds)]
+async fn join(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild = msg.guild(&ctx.cache).await.ok_or("Command works only in guilds")?;
+    let channel_id = guild.voice_states.get(&msg.author.id)
+        .and_then(|state| state.channel_id);
+
+    let connect_to = match channel_id {
+        Some(channel) => channel,
+        None => {
+            msg.reply(ctx, "You're not in a voice channel").await?;
+            return Ok(());
+        }
+    };
+
+    let manager = songbird::get(ctx).await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let (_handle, result) = manager.join(guild.id, connect_to).await;
+
+    let message = match result {
+        Ok(_) => format!("Joined {}", connect_to.mention()),
+        Err(err) => format!("Couldn't join the channel: {}", err),
+    };
+
+    msg.reply(ctx, message).await?;
+
+    Ok(())
+}
+
+#[command]
+#[only_in(guilds)]
+async fn leave(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+
+    let manager = songbird::get(ctx).await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let message = if manager.get(guild_id).is_some() {
+        match manager.remove(guild_id).await {
+            Ok(_) => "Left the voice channel".to_string(),
+            Err(err) => format!("Couldn't leave the channel: {}", err),
+        }
+    } else {
+        "Not in a voice channel".to_string()
+    };
+
+    msg.reply(ctx, message).await?;
+
+    Ok(())
+}
+
+#[command("play")]
+#[only_in(guilds)]
+#[min_args(1)]
+async fn play_track(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let url = args.message().to_string();
+
+    if !url.starts_with("http") {
+        msg.reply(ctx, "That doesn't look like a URL").await?;
+        return Ok(());
+    }
+
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+
+    let manager = songbird::get(ctx).await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let message = match manager.get(guild_id) {
+        Some(call) => match input::ytdl(&url).await {
+            Ok(source) => {
+                call.lock().await.enqueue_source(source);
+
+                format!("Queued: {}", url)
+            },
+            Err(err) => format!("Couldn't fetch that track: {}", err),
+        },
+        None => "Not in a voice channel, use `join` first".to_string(),
+    };
+
+    msg.reply(ctx, message).await?;
+
+    Ok(())
+}
+
+#[command]
+#[only_in(guilds)]
+async fn skip(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+
+    let manager = songbird::get(ctx).await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let message = if let Some(call) = manager.get(guild_id) {
+        let handler = call.lock().await;
+        let queue = handler.queue();
+        let _ = queue.skip();
+
+        format!("Skipped, {} track(s) remaining", queue.len())
+    } else {
+        "Not in a voice channel".to_string()
+    };
+
+    msg.reply(ctx, message).await?;
+
+    Ok(())
+}
+
+#[command]
+#[only_in(guilds)]
+async fn stop(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+
+    let manager = songbird::get(ctx).await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let message = if let Some(call) = manager.get(guild_id) {
+        call.lock().await.queue().stop();
+
+        "Stopped playback and cleared the queue".to_string()
+    } else {
+        "Not in a voice channel".to_string()
+    };
+
+    msg.reply(ctx, message).await?;
+
+    Ok(())
+}
+
+#[command]
+#[only_in(guilds)]
+async fn queue(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+
+    let manager = songbird::get(ctx).await
+        .expect("Songbird Voice client placed in at initialisation.")
+        .clone();
+
+    let message = if let Some(call) = manager.get(guild_id) {
+        let handler = call.lock().await;
+        let tracks = handler.queue().current_queue();
+
+        if tracks.is_empty() {
+            "Nothing queued".to_string()
+        } else {
+            format!("{} track(s) queued", tracks.len())
+        }
+    } else {
+        "Not in a voice channel".to_string()
+    };
+
+    msg.reply(ctx, message).await?;
+
+    Ok(())
+}
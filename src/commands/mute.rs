@@ -1,4 +1,3 @@
-use std::sync::Arc;
 use serenity::{
     prelude::*,
     model::{
@@ -12,80 +11,98 @@ use serenity::{
     },
 };
 
-use crate::Config;
+use crate::api::send_split_in_card;
+use crate::config::get_guild_options;
 
 #[command]
+#[only_in(guilds)]
 #[delimiters(" ")]
 #[min_args(1)]
 #[max_args(1)]
 async fn mute(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
-    let config_lock = get_config_lock(ctx).await;
-    let config = config_lock.read().await;
-    let mute_role_id = config.mute_role_id.unwrap();
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+    let mute_role_id = match get_guild_options(ctx, guild_id).await.mute_role_id {
+        Some(role_id) => role_id,
+        None => {
+            msg.reply(&ctx.http, "No mute role configured, ask an owner to run `settings set mute_role @role`").await?;
+
+            return Ok(());
+        }
+    };
+
     let guild = get_guild(&ctx, msg).await?;
     let mut member = get_member(&ctx, &mut args, guild).await?;
-    
+
     let message = match member.add_role(&ctx.http, mute_role_id).await {
         Ok(_) => format!("{} was muted", member),
         Err(err) => format!("Couldn't mute {}: {}", member, err),
     };
-    
+
     msg.reply(&ctx.http, message).await?;
-    
+
     Ok(())
 }
 
 #[command]
+#[only_in(guilds)]
 #[delimiters(" ")]
 #[min_args(1)]
 #[max_args(1)]
 async fn unmute(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
-    let config_lock = get_config_lock(ctx).await;
-    let config = config_lock.read().await;
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+    let mute_role_id = match get_guild_options(ctx, guild_id).await.mute_role_id {
+        Some(role_id) => role_id,
+        None => {
+            msg.reply(&ctx.http, "No mute role configured, ask an owner to run `settings set mute_role @role`").await?;
+
+            return Ok(());
+        }
+    };
+
     let guild = get_guild(&ctx, msg).await?;
-    let mute_role_id = config.mute_role_id.unwrap();
     let mut member = get_member(&ctx, &mut args, guild).await?;
-    
+
     let message = match member.remove_role(&ctx.http, mute_role_id).await {
         Ok(_) => format!("{} was unmuted", member),
         Err(err) => format!("Couldn't unmute {}: {}", member, err),
     };
-    
+
     msg.reply(&ctx.http, message).await?;
 
     Ok(())
 }
 
 #[command]
+#[only_in(guilds)]
 async fn muted(ctx: &Context, msg: &Message) -> CommandResult {
-    let config_lock = get_config_lock(ctx).await;
-    let config = config_lock.read().await;
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+    let mute_role_id = match get_guild_options(ctx, guild_id).await.mute_role_id {
+        Some(role_id) => role_id,
+        None => {
+            msg.reply(&ctx.http, "No mute role configured, ask an owner to run `settings set mute_role @role`").await?;
+
+            return Ok(());
+        }
+    };
+
     let guild = get_guild(&ctx, msg).await?;
-    let mute_role_id = config.mute_role_id.unwrap();
     let members = guild.members
         .iter()
         .filter(|(_, member)| member.roles.contains(&mute_role_id))
         .map(|(_, member)| member.to_string())
         .collect::<Vec<String>>();
 
-    let message = if members.len() > 0 {
-        format!("Currently muted members: {}", members.join(", "))
+    let message = if !members.is_empty() {
+        format!("Currently muted members:\n{}", members.join("\n"))
     } else {
-        format!("No members are currently muted")
+        "No members are currently muted".to_string()
     };
 
-    msg.reply(&ctx.http, message).await?;
+    send_split_in_card(ctx, msg.channel_id, &message).await?;
 
     Ok(())
 }
 
-async fn get_config_lock(ctx: &Context) -> Arc<RwLock<Config>> {
-    ctx.data.read().await
-        .get::<Config>()
-        .expect("Missing Config in Context")
-        .clone()
-}
-
 async fn get_guild(ctx: &&Context, msg: &Message) -> Result<Guild, String> {
     let guild = msg.channel_id
         .to_channel(&ctx.http).await
@@ -94,7 +111,7 @@ async fn get_guild(ctx: &&Context, msg: &Message) -> Result<Guild, String> {
         .ok_or("Command works only in channels")?
         .guild(&ctx.cache).await
         .ok_or("nope")?;
-    
+
     Ok(guild)
 }
 
@@ -107,7 +124,7 @@ async fn get_member(ctx: &&Context, args: &mut Args, guild: Guild) -> Result<Mem
             guild
                 .member_named(user_name)
                 .ok_or(format!("couldn't find member: {}", user_name))
-                .map(Clone::clone)
+                .cloned()
         }
     }
 }
@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use log::error;
+use rand::Rng;
+use serenity::{
+    prelude::*,
+    model::{
+        channel::Message,
+        guild::Member,
+        id::RoleId,
+    },
+    framework::standard::{
+        CommandResult,
+        macros::*,
+    },
+};
+
+use crate::commands::admin::outranks;
+use crate::config::get_guild_options;
+
+const SURVIVAL_MESSAGES: &[&str] = &[
+    "*click* ... empty chamber. You live to pull the trigger another day.",
+    "*click* ... nothing. Lucky you.",
+];
+
+#[command]
+#[only_in(guilds)]
+async fn roulette(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+    let options = get_guild_options(ctx, guild_id).await;
+
+    let loses = rand::thread_rng().gen_range(0..6) == 0;
+
+    if !loses {
+        let message = SURVIVAL_MESSAGES[rand::thread_rng().gen_range(0..SURVIVAL_MESSAGES.len())];
+        msg.reply(ctx, message).await?;
+
+        return Ok(());
+    }
+
+    let member = guild_id.member(ctx, msg.author.id).await?;
+
+    if options.roulette_kick {
+        apply_kick_stake(ctx, msg, member).await?;
+    } else {
+        apply_timeout_stake(ctx, msg, member, options.mute_role_id, options.roulette_mute_minutes).await?;
+    }
+
+    Ok(())
+}
+
+async fn apply_kick_stake(ctx: &Context, msg: &Message, member: Member) -> CommandResult {
+    let bot_id = ctx.cache.current_user_id().await;
+    let bot_member = msg.guild_id.unwrap().member(ctx, bot_id).await?;
+
+    if !outranks(&bot_member, &member, &ctx.cache).await {
+        msg.reply(ctx, "*BANG* ... but the bot isn't high enough in the role hierarchy to enforce that, you're spared.").await?;
+
+        return Ok(());
+    }
+
+    let message = match member.kick(&ctx.http).await {
+        Ok(_) => "*BANG* Unlucky. See you on the other side.".to_string(),
+        Err(err) => format!("*BANG* Unlucky, but the bot couldn't kick you: {}", err),
+    };
+
+    msg.reply(ctx, message).await?;
+
+    Ok(())
+}
+
+async fn apply_timeout_stake(
+    ctx: &Context,
+    msg: &Message,
+    mut member: Member,
+    mute_role_id: Option<RoleId>,
+    minutes: u64,
+) -> CommandResult {
+    let mute_role_id = match mute_role_id {
+        Some(role_id) => role_id,
+        None => {
+            msg.reply(ctx, "*BANG*, but no mute role is configured to enforce the stake").await?;
+
+            return Ok(());
+        }
+    };
+
+    let bot_id = ctx.cache.current_user_id().await;
+    let bot_member = msg.guild_id.unwrap().member(ctx, bot_id).await?;
+
+    if !outranks(&bot_member, &member, &ctx.cache).await {
+        msg.reply(ctx, "*BANG* ... but the bot isn't high enough in the role hierarchy to enforce that, you're spared.").await?;
+
+        return Ok(());
+    }
+
+    if let Err(err) = member.add_role(&ctx.http, mute_role_id).await {
+        msg.reply(ctx, format!("*BANG* Unlucky, but the bot couldn't mute you: {}", err)).await?;
+
+        return Ok(());
+    }
+
+    msg.reply(ctx, format!("*BANG* Unlucky. You're muted for {} minute(s).", minutes)).await?;
+
+    let http = ctx.http.clone();
+
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(minutes * 60)).await;
+
+        if let Err(err) = member.remove_role(&http, mute_role_id).await {
+            error!("Error when tried to lift a roulette mute: {}", err)
+        }
+    });
+
+    Ok(())
+}
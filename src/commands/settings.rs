@@ -0,0 +1,83 @@
+use serenity::{
+    prelude::*,
+    model::channel::Message,
+    framework::standard::{
+        Args, CommandResult,
+        macros::*,
+    },
+};
+
+use crate::config::{get_guild_options, update_guild_options};
+
+#[command]
+#[only_in(guilds)]
+#[min_args(1)]
+async fn set(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+    let key = args.single::<String>()?;
+
+    let message = match key.as_str() {
+        "mute_role" => match msg.mention_roles.first() {
+            Some(role_id) => {
+                let role_id = *role_id;
+
+                update_guild_options(ctx, guild_id, |options| options.mute_role_id = Some(role_id)).await;
+
+                format!("mute_role set to <@&{}>", role_id)
+            },
+            None => "Mention a role to set as the mute role".to_string(),
+        },
+        "roulette_mode" => match args.single::<String>().as_deref() {
+            Ok("kick") => {
+                update_guild_options(ctx, guild_id, |options| options.roulette_kick = true).await;
+
+                "roulette_mode set to kick".to_string()
+            },
+            Ok("timeout") => {
+                update_guild_options(ctx, guild_id, |options| options.roulette_kick = false).await;
+
+                "roulette_mode set to timeout".to_string()
+            },
+            _ => "Expected `kick` or `timeout`".to_string(),
+        },
+        "roulette_minutes" => match args.single::<u64>() {
+            Ok(minutes) => {
+                update_guild_options(ctx, guild_id, |options| options.roulette_mute_minutes = minutes).await;
+
+                format!("roulette_minutes set to {}", minutes)
+            },
+            Err(_) => "Expected a number of minutes".to_string(),
+        },
+        other => format!("Unknown setting `{}`", other),
+    };
+
+    msg.reply(ctx, message).await?;
+
+    Ok(())
+}
+
+#[command]
+#[only_in(guilds)]
+async fn show(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or("Command works only in guilds")?;
+    let options = get_guild_options(ctx, guild_id).await;
+
+    let mute_role = options.mute_role_id
+        .map(|id| format!("<@&{}>", id))
+        .unwrap_or_else(|| "not set".to_string());
+
+    let welcome_channel = options.welcome_channel_id
+        .map(|id| format!("<#{}>", id))
+        .unwrap_or_else(|| "not set".to_string());
+
+    let roulette_mode = if options.roulette_kick { "kick" } else { "timeout" };
+
+    let message = format!(
+        "**mute_role**: {}\n**ghost_ping**: {}\n**welcome_channel**: {}\n**roulette_mode**: {}\n**roulette_minutes**: {}",
+        mute_role, options.ghost_ping, welcome_channel, roulette_mode, options.roulette_mute_minutes,
+    );
+
+    msg.reply(ctx, message).await?;
+
+    Ok(())
+}
@@ -0,0 +1,103 @@
+use std::{collections::HashMap, fs, sync::Arc};
+
+use log::error;
+use serde::{Deserialize, Serialize};
+use serenity::{
+    prelude::*,
+    model::id::{ChannelId, GuildId, RoleId},
+};
+
+const CONFIG_PATH: &str = "config.toml";
+const DEFAULT_ROULETTE_MUTE_MINUTES: u64 = 5;
+
+/// Per-guild bot configuration, loaded once at startup and flushed to disk
+/// whenever a guild's entry changes.
+///
+/// Guilds are keyed by the string form of their `GuildId`: `toml` only
+/// supports string table keys, while `GuildId` serializes as a bare integer.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Config {
+    guilds: HashMap<String, GuildOptions>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuildOptions {
+    pub mute_role_id: Option<RoleId>,
+    pub ghost_ping: bool,
+    pub welcome_channel_id: Option<ChannelId>,
+    /// When `true`, losing at `roulette` kicks the member instead of
+    /// temporarily muting them. Off by default since it's the harsher stake.
+    pub roulette_kick: bool,
+    pub roulette_mute_minutes: u64,
+}
+
+impl Default for GuildOptions {
+    fn default() -> Self {
+        GuildOptions {
+            mute_role_id: None,
+            ghost_ping: false,
+            welcome_channel_id: None,
+            roulette_kick: false,
+            roulette_mute_minutes: DEFAULT_ROULETTE_MUTE_MINUTES,
+        }
+    }
+}
+
+impl Config {
+    pub fn load() -> Config {
+        fs::read_to_string(CONFIG_PATH)
+            .ok()
+            .and_then(|content| toml::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> std::io::Result<()> {
+        let content = toml::to_string_pretty(self).expect("Config should always serialize");
+
+        fs::write(CONFIG_PATH, content)
+    }
+
+    pub fn guild(&self, guild_id: GuildId) -> GuildOptions {
+        self.guilds.get(&guild_id.0.to_string()).cloned().unwrap_or_default()
+    }
+
+    pub fn guild_mut(&mut self, guild_id: GuildId) -> &mut GuildOptions {
+        self.guilds.entry(guild_id.0.to_string()).or_default()
+    }
+}
+
+pub struct ConfigStore;
+
+impl TypeMapKey for ConfigStore {
+    type Value = Arc<RwLock<Config>>;
+}
+
+pub async fn get_guild_options(ctx: &Context, guild_id: GuildId) -> GuildOptions {
+    let store = ctx.data.read().await
+        .get::<ConfigStore>()
+        .expect("Missing ConfigStore in Context")
+        .clone();
+
+    let config = store.read().await;
+
+    config.guild(guild_id)
+}
+
+pub async fn update_guild_options<F>(ctx: &Context, guild_id: GuildId, f: F)
+where
+    F: FnOnce(&mut GuildOptions),
+{
+    let store = ctx.data.read().await
+        .get::<ConfigStore>()
+        .expect("Missing ConfigStore in Context")
+        .clone();
+
+    let mut config = store.write().await;
+
+    f(config.guild_mut(guild_id));
+
+    if let Err(err) = config.save() {
+        error!("Error when tried to save config: {}", err);
+    }
+}
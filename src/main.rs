@@ -1,7 +1,7 @@
-use std::{collections::{HashSet}, env};
+use std::{collections::{HashMap, HashSet, VecDeque}, env, sync::Arc};
 
 use maplit::hashset;
-use log::{info, error};
+use log::error;
 use serenity::{
     prelude::*,
     async_trait,
@@ -13,18 +13,46 @@ use serenity::{
     http::Http,
     model::{
         channel::{Channel, Message},
-        id::{ChannelId, GuildId, UserId},
-        
+        id::{ChannelId, GuildId, MessageId, UserId},
+
         gateway::{Activity as SerenityActivity, Ready},
-        user::{OnlineStatus},
+        user::{OnlineStatus, User},
     },
 };
 
+mod api;
 mod commands;
+mod config;
 
 use commands::{
     emoji::*,
+    admin::*,
+    ghost_ping::*,
+    mute::*,
+    roulette::*,
+    settings::*,
 };
+#[cfg(feature = "music")]
+use commands::music::*;
+use config::{Config, ConfigStore, get_guild_options};
+
+// Holds the handful of recent messages per channel needed to report ghost
+// pings: a user deletes a message that mentioned someone, and we still have
+// it cached long enough to call it out.
+struct MessageCache;
+
+impl TypeMapKey for MessageCache {
+    type Value = Arc<RwLock<HashMap<ChannelId, VecDeque<CachedMessage>>>>;
+}
+
+const MESSAGE_CACHE_CAPACITY: usize = 100;
+
+struct CachedMessage {
+    id: MessageId,
+    content: String,
+    author: User,
+    mentions: Vec<User>,
+}
 
 // The framework provides two built-in help commands for you to use.
 // But you can also make your own customized help command that forwards
@@ -73,7 +101,7 @@ async fn my_help(
 }
 
 #[group]
-#[commands(ping, do_you_know, version)]
+#[commands(ping, do_you_know, version, roulette)]
 struct General;
 
 #[group]
@@ -104,9 +132,38 @@ struct Activity;
 #[owners_only]
 #[only_in(guilds)]
 #[summary = "Commands for server owners"]
-#[commands(slow_mode, activity)]
+#[commands(slow_mode)]
+#[sub_groups(Activity)]
 struct Owner;
 
+#[group]
+#[only_in(guilds)]
+#[summary = "Moderation commands"]
+#[commands(kick, ban, ghost_ping)]
+struct Admin;
+
+#[group]
+#[only_in(guilds)]
+#[summary = "Mute and unmute members"]
+#[commands(mute, unmute, muted)]
+struct Mute;
+
+#[group]
+#[owners_only]
+#[only_in(guilds)]
+#[prefixes("settings")]
+#[summary = "Read and change this server's bot configuration"]
+#[default_command(show)]
+#[commands(show, set)]
+struct Settings;
+
+#[cfg(feature = "music")]
+#[group]
+#[prefixes("music", "m")]
+#[summary = "Play music in a voice channel"]
+#[commands(join, leave, play_track, skip, stop, queue)]
+struct Music;
+
 struct Handler;
 
 #[async_trait]
@@ -136,19 +193,61 @@ impl EventHandler for Handler {
     async fn message(&self, ctx: Context, msg: Message) {
         let bot_user_ud = ctx.cache.current_user_id().await;
         
-        if msg.content == format!("<@!{}> po ile schab?", bot_user_ud.to_string()) {
+        if msg.content == format!("<@!{}> po ile schab?", bot_user_ud) {
             let message = if msg.author.name == "bartsmykla" {
                 "dla Ciebie dycha"
             } else {
                 "nie stać cię"
             };
             
-            if let Err(e) = msg.reply(ctx, message).await {
+            if let Err(e) = msg.reply(&ctx, message).await {
                 error!("Error when tried to send a message: {}", e)
             }
         }
+
+        // Bot commands aren't interesting for ghost-ping reporting, and
+        // caching them would just waste space in the per-channel buffer.
+        if !msg.content.starts_with('!') {
+            cache_message(&ctx, &msg).await;
+        }
     }
-    
+
+    async fn message_delete(&self, ctx: Context, channel_id: ChannelId, deleted_message_id: MessageId, guild_id: Option<GuildId>) {
+        let guild_id = match guild_id {
+            Some(guild_id) => guild_id,
+            None => return,
+        };
+
+        if !get_guild_options(&ctx, guild_id).await.ghost_ping {
+            return;
+        }
+
+        let cached = take_cached_message(&ctx, channel_id, deleted_message_id).await;
+
+        let cached = match cached {
+            Some(cached) if !cached.mentions.is_empty() => cached,
+            _ => return,
+        };
+
+        let pinged = cached.mentions.iter()
+            .map(|user| user.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let result = channel_id.send_message(&ctx.http, |m| m
+            .embed(|e| e
+                .title("Ghost ping detected")
+                .field("Author", cached.author.to_string(), true)
+                .field("Pinged", pinged, true)
+                .description(cached.content)
+            )
+        ).await;
+
+        if let Err(e) = result {
+            error!("Error when tried to report a ghost ping: {}", e)
+        }
+    }
+
     async fn ready(&self, context: Context, _: Ready) {
 
         let version = env::var("SMYKLOT_VERSION")
@@ -217,20 +316,76 @@ async fn main() {
         .group(&SYSTEMS_GROUP)
         .group(&EMOJI_GROUP)
         .group(&OWNER_GROUP)
-        .group(&ACTIVITY_GROUP);
+        .group(&ACTIVITY_GROUP)
+        .group(&ADMIN_GROUP)
+        .group(&MUTE_GROUP)
+        .group(&SETTINGS_GROUP);
 
-    let mut client = Client::builder(token)
+    #[cfg(feature = "music")]
+    let framework = framework.group(&MUSIC_GROUP);
+
+    let client_builder = Client::builder(token)
         .event_handler(Handler)
-        .framework(framework)
+        .framework(framework);
+
+    #[cfg(feature = "music")]
+    let client_builder = {
+        use songbird::SerenityInit;
+
+        client_builder.register_songbird()
+    };
+
+    let mut client = client_builder
         .await
         .expect("Error creating client");
 
+    {
+        let mut data = client.data.write().await;
+
+        data.insert::<MessageCache>(Arc::new(RwLock::new(HashMap::new())));
+        data.insert::<ConfigStore>(Arc::new(RwLock::new(Config::load())));
+    }
+
     // start listening for events by starting a single shard
     if let Err(why) = client.start().await {
         error!("An error occurred while running the client: {:?}", why);
     }
 }
 
+async fn cache_message(ctx: &Context, msg: &Message) {
+    let cache_lock = ctx.data.read().await
+        .get::<MessageCache>()
+        .expect("Missing MessageCache in Context")
+        .clone();
+
+    let mut cache = cache_lock.write().await;
+    let channel_messages = cache.entry(msg.channel_id).or_insert_with(VecDeque::new);
+
+    channel_messages.push_back(CachedMessage {
+        id: msg.id,
+        content: msg.content.clone(),
+        author: msg.author.clone(),
+        mentions: msg.mentions.clone(),
+    });
+
+    while channel_messages.len() > MESSAGE_CACHE_CAPACITY {
+        channel_messages.pop_front();
+    }
+}
+
+async fn take_cached_message(ctx: &Context, channel_id: ChannelId, message_id: MessageId) -> Option<CachedMessage> {
+    let cache_lock = ctx.data.read().await
+        .get::<MessageCache>()
+        .expect("Missing MessageCache in Context")
+        .clone();
+
+    let mut cache = cache_lock.write().await;
+    let channel_messages = cache.get_mut(&channel_id)?;
+    let index = channel_messages.iter().position(|cached| cached.id == message_id)?;
+
+    channel_messages.remove(index)
+}
+
 // A function which acts as a "check", to determine whether to call a command.
 //
 // In this case, this command checks to ensure you are the owner of the message
@@ -265,7 +420,7 @@ async fn owner_check(_: &Context, msg: &Message, _: &mut Args, _: &CommandOption
 async fn play(ctx: &Context, _msg: &Message, args: Args) -> CommandResult {
     let name = args.message();
     
-    ctx.set_activity(SerenityActivity::playing(&name)).await;
+    ctx.set_activity(SerenityActivity::playing(name)).await;
     
     Ok(())
 }
@@ -273,13 +428,13 @@ async fn play(ctx: &Context, _msg: &Message, args: Args) -> CommandResult {
 #[command]
 async fn version(ctx: &Context, msg: &Message) -> CommandResult {
     let version = env::var("SMYKLOT_VERSION");
-    
+
     let message = match version {
         Ok(v) if v != "{{version}}" => v,
         _ => String::from("¯\\_(ツ)_/¯")
     };
 
-    msg.reply(ctx, message).await?;
+    api::send_reply(ctx, msg, message).await?;
 
     Ok(())
 }
@@ -339,7 +494,7 @@ async fn windows(ctx: &Context, msg: &Message) -> CommandResult {
 #[command]
 async fn slow_mode(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     let say_content = if let Ok(slow_mode_rate_seconds) = args.single::<u64>() {
-        if let Err(why) = msg.channel_id.edit(&ctx.http, |c| c.slow_mode_rate(slow_mode_rate_seconds)).await {
+        if let Err(why) = msg.channel_id.edit(&ctx.http, |c| c.rate_limit_per_user(slow_mode_rate_seconds)).await {
             println!("Error setting channel's slow mode rate: {:?}", why);
 
             format!("Failed to set slow mode to `{}` seconds.", slow_mode_rate_seconds)
@@ -347,7 +502,10 @@ async fn slow_mode(ctx: &Context, msg: &Message, mut args: Args) -> CommandResul
             format!("Successfully set slow mode rate to `{}` seconds.", slow_mode_rate_seconds)
         }
     } else if let Some(Channel::Guild(channel)) = msg.channel_id.to_channel_cached(&ctx.cache).await {
-        format!("Current slow mode rate is `{}` seconds.", channel.slow_mode_rate.unwrap_or(0))
+        #[allow(deprecated)]
+        let rate = channel.slow_mode_rate.unwrap_or(0);
+
+        format!("Current slow mode rate is `{}` seconds.", rate)
     } else {
         "Failed to find channel in cache.".to_string()
     };